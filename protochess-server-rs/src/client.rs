@@ -0,0 +1,61 @@
+use tokio::sync::mpsc;
+use uuid::Uuid;
+use crate::client_message::ClientResponse;
+
+/// A human client is backed by a channel to its websocket task; a bot seat
+/// carries only a search depth. The room does not use that depth to choose the
+/// bot's moves: on the bot's turn it plays the first legal move, with no search.
+enum ClientKind {
+    Human(mpsc::UnboundedSender<ClientResponse>),
+    Bot { depth: u8 },
+}
+
+/// Whether a client occupies a seat in the turn order or is just watching.
+/// Newly connected clients start as `Spectator` until the room assigns them a seat.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClientRole {
+    Player(u8),
+    Spectator,
+}
+
+pub struct Client {
+    pub id: Uuid,
+    pub name: String,
+    pub role: ClientRole,
+    /// Opaque, long-lived session token a human client can present via
+    /// `ClientRequest::Resume` to rebind to this seat after a dropped connection.
+    pub token: Uuid,
+    kind: ClientKind,
+}
+
+impl Client {
+    pub fn new(id: Uuid, name: String, sender: mpsc::UnboundedSender<ClientResponse>) -> Client {
+        Client { id, name, role: ClientRole::Spectator, token: Uuid::new_v4(), kind: ClientKind::Human(sender) }
+    }
+
+    /// A pseudo-client occupying `seat`, labelled with a search depth of `depth`
+    /// plies. The room plays the first legal move for it; no search is run.
+    pub fn new_bot(name: String, depth: u8, seat: u8) -> Client {
+        Client { id: Uuid::new_v4(), name, role: ClientRole::Player(seat), token: Uuid::new_v4(), kind: ClientKind::Bot { depth } }
+    }
+
+    pub fn is_bot(&self) -> bool {
+        matches!(self.kind, ClientKind::Bot { .. })
+    }
+
+    /// The search depth for this seat, if it's a bot.
+    pub fn bot_depth(&self) -> Option<u8> {
+        match self.kind {
+            ClientKind::Bot { depth } => Some(depth),
+            ClientKind::Human(_) => None,
+        }
+    }
+
+    pub fn try_send(&self, response: ClientResponse) {
+        if let ClientKind::Human(sender) = &self.kind {
+            if let Err(e) = sender.send(response) {
+                eprintln!("Error sending to client {}: {}", self.id, e);
+            }
+        }
+    }
+}
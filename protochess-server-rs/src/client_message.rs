@@ -0,0 +1,139 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub struct Turn {
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    pub promote_to: Option<char>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Piece {
+    pub owner: u8,
+    pub x: u8,
+    pub y: u8,
+    pub piece_type: char,
+}
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    pub x: u8,
+    pub y: u8,
+    pub tile_type: char,
+}
+
+/// A custom piece type the leader has registered for the match, by glyph and
+/// movement pattern, using whatever notation `protochess_engine_rs` accepts.
+#[derive(Clone, Debug)]
+pub struct CustomPieceDef {
+    pub glyph: char,
+    pub movement_pattern: String,
+}
+
+/// How many votes a side's pending move needs before it's played, when more
+/// than one client shares that seat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteThreshold {
+    Majority,
+    Unanimous,
+}
+
+/// Match settings the leader configures in the lobby before `StartGame`.
+#[derive(Clone, Debug)]
+pub struct GameConfig {
+    pub width: u8,
+    pub height: u8,
+    pub fen: Option<String>,
+    pub custom_pieces: Vec<CustomPieceDef>,
+    pub vote_threshold: VoteThreshold,
+}
+
+impl Default for GameConfig {
+    fn default() -> GameConfig {
+        GameConfig {
+            width: 8,
+            height: 8,
+            fen: None,
+            custom_pieces: Vec::new(),
+            vote_threshold: VoteThreshold::Majority,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ClientRequest {
+    ChatMessage(String),
+    TakeTurn(Turn),
+    GameState,
+    StartGame,
+    SwitchLeader(u8),
+    ListPlayers,
+    MovesFrom(u8, u8),
+    /// Fill an open seat with a bot opponent labelled with `difficulty` as its search
+    /// depth. The bot plays the first legal move; `difficulty` does not change its play.
+    AddBot { difficulty: u8 },
+    /// Remove the bot occupying `seat`, leaving it open again.
+    RemoveBot(u8),
+    /// Leader-only: replace the pending lobby config. Rejected once the game has started.
+    SetConfig(GameConfig),
+    /// Give up (or decline) a seat and watch instead.
+    JoinAsSpectator,
+    /// Take a seat, becoming a player. A seat may be shared by several humans as a
+    /// team; rejected if a bot holds the seat or it is reserved for a reconnect.
+    SitDown(u8),
+    /// Rebind this (freshly reconnected) client to the seat reserved for `token`,
+    /// as issued by the `ClientResponse::Session` sent on the original join.
+    Resume(Uuid),
+}
+
+#[derive(Clone, Debug)]
+pub enum ClientResponse {
+    ChatMessage { from: String, content: String },
+    PlayerList { player_num: Option<u8>, you: String, players: Vec<String>, spectators: Vec<String> },
+    MovesFrom { from: (u8, u8), to: Vec<(u8, u8)> },
+    GameState {
+        width: u8,
+        height: u8,
+        winner: Option<String>,
+        to_move: u8,
+        to_move_in_check: bool,
+        in_check_kings: Option<Vec<Piece>>,
+        last_turn: Option<Turn>,
+        tiles: Vec<Tile>,
+        pieces: Vec<Piece>,
+    },
+    /// The lobby's current pending configuration, broadcast whenever it changes.
+    Config(GameConfig),
+    /// This client's session token, sent once on join; present it to `Resume` later.
+    Session(Uuid),
+    /// What changed since the last broadcast, instead of the whole board: pieces
+    /// present only in the new position (added or moved to) and squares present
+    /// only in the old one (vacated or captured on). Carries `winner` too, so a
+    /// checkmating move reports the result in-band instead of requiring a
+    /// follow-up `GameState` poll.
+    GameDelta {
+        added_or_moved: Vec<Piece>,
+        removed: Vec<(u8, u8)>,
+        to_move: u8,
+        to_move_in_check: bool,
+        last_turn: Option<Turn>,
+        winner: Option<String>,
+    },
+    /// Current tally of votes for the side to move's pending ply, sent after every vote.
+    VoteState { to_move: u8, votes: Vec<VoteCount> },
+    /// The leader disconnected and this client (now `clients[0]`) has taken over.
+    LeaderChanged { name: String },
+    /// A player seat emptied out mid-game; the other side may be waiting on a
+    /// turn that can't come until someone reconnects or the seat is reassigned.
+    SeatVacated { seat: u8 },
+    /// The match ended without a winner because a seat was never reclaimed in time.
+    GameAborted { reason: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct VoteCount {
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    pub promote_to: Option<char>,
+    pub count: u8,
+}
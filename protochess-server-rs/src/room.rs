@@ -1,10 +1,13 @@
 use tokio::sync::mpsc;
 use crate::room_message::RoomMessage;
-use crate::client::Client;
-use crate::client_message::{ClientRequest, ClientResponse, Piece, Tile, Turn};
+use crate::client::{Client, ClientRole};
+use crate::client_message::{ClientRequest, ClientResponse, GameConfig, Piece, Tile, Turn, VoteCount, VoteThreshold};
 use uuid::Uuid;
 use lazy_static::lazy_static;
 use protochess_engine_rs::Move;
+use std::time::{Duration, Instant};
+use std::collections::{HashMap, HashSet};
+use tokio::time::Instant as TokioInstant;
 
 
 lazy_static! {
@@ -13,6 +16,34 @@ lazy_static! {
     };
 }
 
+//protochess is a 2-sided game; seats are the two turn-order slots a client
+//(human or bot) can occupy, independent of its position in `clients`
+const NUM_SEATS: u8 = 2;
+
+//How long a disconnected player's seat stays reserved for a `Resume`
+const RECONNECT_GRACE: Duration = Duration::from_secs(60);
+
+//How long a team has to agree on a move before the current plurality auto-commits
+const VOTE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `(from, to, promotion)` identifying a candidate move in the current vote.
+type MoveKey = ((u8, u8), (u8, u8), Option<char>);
+
+/// A player seat held open after its client disconnected, until `expires_at`.
+struct ReservedSeat {
+    token: Uuid,
+    name: String,
+    role: ClientRole,
+    expires_at: Instant,
+}
+
+/// What happened as a result of a `RemoveClient`, for the caller to react to.
+struct RemoveOutcome {
+    was_leader: bool,
+    room_empty: bool,
+    new_leader: Option<String>,
+}
+
 
 pub struct Room {
     //clients[0] is the leader
@@ -21,41 +52,93 @@ pub struct Room {
     winner: Option<String>,
     clients: Vec<Client>,
     last_turn: Option<Turn>,
+    //Pending lobby settings; locked in once `in_progress` flips to true
+    config: GameConfig,
+    in_progress: bool,
+    //Seats belonging to recently-disconnected players, held for RECONNECT_GRACE
+    reserved: Vec<ReservedSeat>,
+    //Pieces as of the last broadcast, to diff against for GameDelta
+    last_snapshot: Vec<(u8, u8, u8, char)>,
+    //Votes cast for the current ply, keyed by candidate move, when a seat is shared by a team
+    vote_tally: HashMap<MoveKey, HashSet<Uuid>>,
+    //Set on the first vote of a ply; the plurality move auto-commits when it elapses
+    vote_deadline: Option<TokioInstant>,
     rx: mpsc::UnboundedReceiver<RoomMessage>,
 }
 
 impl Room {
     pub fn new(rx: mpsc::UnboundedReceiver<RoomMessage>) -> Room {
+        let game = protochess_engine_rs::Game::default();
+        let last_snapshot = game.current_position.pieces_as_tuples();
         Room{
-            game: protochess_engine_rs::Game::default(),
+            game,
             to_move_in_check: false,
             winner: None,
             clients: Vec::new(),
             last_turn: None,
+            config: GameConfig::default(),
+            in_progress: false,
+            reserved: Vec::new(),
+            last_snapshot,
+            vote_tally: HashMap::new(),
+            vote_deadline: None,
             rx,
         }
     }
 
     pub async fn run(&mut self){
-        while let Some(message) = self.rx.recv().await {
+        loop {
+            let message = tokio::select! {
+                maybe_message = self.rx.recv() => {
+                    match maybe_message {
+                        Some(message) => message,
+                        None => break,
+                    }
+                }
+                _ = Self::wait_for_vote_deadline(self.vote_deadline) => {
+                    self.resolve_vote_timeout();
+                    continue;
+                }
+                _ = Self::wait_for_reservation_expiry(self.reserved.iter().map(|r| r.expires_at).min()) => {
+                    self.expire_reservations();
+                    continue;
+                }
+            };
             match message {
-                RoomMessage::AddClient(client) => {
+                RoomMessage::AddClient(mut client) => {
+                    //Claim an open seat if one exists and the game hasn't started;
+                    //otherwise the client joins as a spectator
+                    if !self.in_progress {
+                        if let Some(seat) = self.next_open_seat() {
+                            client.role = ClientRole::Player(seat);
+                        }
+                    }
+                    client.try_send(ClientResponse::Session(client.token));
+                    client.try_send(ClientResponse::Config(self.config.clone()));
                     client.try_send(self.serialize_game());
                     self.clients.push(client);
                     self.broadcast_player_list();
                 }
                 RoomMessage::RemoveClient(id) => {
-                    if let Some(index) = self.clients.iter().position(|x| x.id == id){
-                        self.clients.remove(index);
-                        //Broadcast the new player list
-                        self.broadcast_player_list();
-                    }else{
-                        eprintln!("no user found at id");
+                    match self.remove_client(id) {
+                        Some(outcome) if outcome.room_empty => println!("room is now empty"),
+                        Some(outcome) if outcome.was_leader => {
+                            if let Some(name) = outcome.new_leader {
+                                println!("leader disconnected, {} is now the leader", name);
+                            }
+                        }
+                        Some(_) => {}
+                        None => eprintln!("no user found at id"),
                     }
                 }
                 RoomMessage::External(requester_id, client_request) => {
-                    if let Some(player_num) = self.clients.iter().position(|x| x.id == requester_id){
-                        let requester_client = &self.clients[player_num];
+                    if let Some(requester_index) = self.clients.iter().position(|x| x.id == requester_id){
+                        let requester_client = &self.clients[requester_index];
+                        let requester_seat = match requester_client.role {
+                            ClientRole::Player(seat) => Some(seat),
+                            ClientRole::Spectator => None,
+                        };
+                        let is_leader = requester_index == 0;
                         match client_request {
                             ClientRequest::ChatMessage(m) => {
                                 //Send message to other users in the room
@@ -65,59 +148,102 @@ impl Room {
                                 }, requester_id);
                             }
                             ClientRequest::TakeTurn(turn) => {
-                                let from = turn.from;
-                                let to = turn.to;
-                                //Check if it's this player's turn
-                                if player_num as u8 == self.game.get_whos_turn() {
-                                    let (x1, y1) = from;
-                                    let (x2, y2) = to;
-                                    println!("taketurn requested {} {} {} {}", x1, y1, x2, y2);
-                                    let move_gen:&protochess_engine_rs::MoveGenerator = &MOVEGEN;
-                                    if self.game.make_move(move_gen, x1, y1, x2, y2){
-                                        println!("Move successful");
-                                        // TODO add promotion
-                                        self.last_turn = Some(Turn {
-                                            promote_to: None,
-                                            from,
-                                            to
-                                        });
-
-                                        //Calculate if the position is in check after making this move
-                                        self.to_move_in_check = move_gen.in_check(&mut self.game.current_position);
-                                        if self.to_move_in_check {
-                                            if move_gen.count_legal_moves(&mut self.game.current_position) == 0 {
-                                                //We have a winner!
-                                                self.winner = Some(requester_client.name.clone());
-                                            }
-                                        }
-                                        //See if we have any more moves
-                                        self.broadcast_game_update();
-
-                                    }
+                                //Check if the game has started and it's this player's side to move.
+                                //When a seat is shared by a team this registers a vote rather than
+                                //playing the move outright; a lone occupant's vote always reaches
+                                //the threshold immediately, so solo play behaves exactly as before.
+                                if self.in_progress && requester_seat == Some(self.game.get_whos_turn()) {
+                                    self.register_vote(requester_seat.unwrap(), turn, requester_id);
                                 }
-
                             }
                             ClientRequest::GameState => {
                                 println!("gamestate requested");
                                 requester_client.try_send(self.serialize_game());
                             }
                             ClientRequest::StartGame => {
-                                println!("start game requested")
+                                //SetConfig already rejects configs that don't build, but check
+                                //again here rather than trust that invariant and risk a panic.
+                                if is_leader && !self.in_progress {
+                                    if let Ok(game) = self.build_game() {
+                                        println!("start game requested");
+                                        self.game = game;
+                                        self.last_snapshot = self.game.current_position.pieces_as_tuples();
+                                        self.in_progress = true;
+                                        self.to_move_in_check = false;
+                                        self.winner = None;
+                                        //This is a full reset, not an incremental move: clients were
+                                        //last shown the lobby's default board, so a delta against the
+                                        //snapshot we just took (of the same new game) would be empty
+                                        //and they'd keep rendering the old position. Send the whole
+                                        //state, the same as a newly-joined client gets.
+                                        self.broadcast(self.serialize_game());
+                                        self.play_bot_turns();
+                                    } else {
+                                        eprintln!("refusing to start: lobby config no longer builds a game");
+                                    }
+                                }
+                            }
+                            ClientRequest::SetConfig(config) => {
+                                //Only the leader may change lobby settings, and only before start.
+                                //Reject configs that wouldn't actually build a game (e.g. a
+                                //malformed FEN) instead of accepting them and failing at StartGame.
+                                if is_leader && !self.in_progress && Self::build_game_from(&config).is_ok() {
+                                    self.config = config;
+                                    self.broadcast(ClientResponse::Config(self.config.clone()));
+                                }
                             }
                             ClientRequest::SwitchLeader(new_leader) => {
-                                if player_num == 0 && (new_leader as usize) < self.clients.len() {
+                                if is_leader && (new_leader as usize) < self.clients.len() {
                                     self.clients.swap(0, new_leader as usize);
                                 }
                             }
                             ClientRequest::ListPlayers => {
-                                requester_client.try_send(ClientResponse::PlayerList {
-                                    player_num: player_num as u8,
-                                    you: format!("{}", requester_client.name),
-                                    names: self.clients.iter().map(|x| x.name.clone()).collect()
-                                })
+                                requester_client.try_send(self.player_list_response(requester_seat, &requester_client.name));
+                            }
+                            ClientRequest::JoinAsSpectator => {
+                                self.clients[requester_index].role = ClientRole::Spectator;
+                                self.broadcast_player_list();
+                            }
+                            ClientRequest::SitDown(seat) => {
+                                //A seat may be shared by a team of humans, but not alongside a
+                                //bot, nor one still held in reserve for a disconnected player
+                                if seat < NUM_SEATS && !self.seat_has_bot(seat) && !self.seat_reserved(seat) {
+                                    self.clients[requester_index].role = ClientRole::Player(seat);
+                                    self.broadcast_player_list();
+                                }
+                            }
+                            ClientRequest::Resume(token) => {
+                                let now = Instant::now();
+                                if let Some(pos) = self.reserved.iter().position(|r| r.token == token && r.expires_at > now) {
+                                    let reserved = self.reserved.remove(pos);
+                                    self.clients[requester_index].role = reserved.role;
+                                    self.clients[requester_index].name = reserved.name;
+                                    self.clients[requester_index].try_send(self.serialize_game());
+                                    self.broadcast_player_list();
+                                }
+                            }
+                            ClientRequest::AddBot { difficulty } => {
+                                //Only the leader may fill an open seat with an engine opponent
+                                if is_leader {
+                                    if let Some(seat) = self.next_open_seat() {
+                                        self.clients.push(Client::new_bot(format!("Bot (depth {})", difficulty), difficulty, seat));
+                                        self.broadcast_player_list();
+                                        self.play_bot_turns();
+                                    }
+                                }
+                            }
+                            ClientRequest::RemoveBot(seat) => {
+                                if is_leader {
+                                    if let Some(index) = self.seat_client_index(seat) {
+                                        if self.clients[index].is_bot() {
+                                            self.clients.remove(index);
+                                            self.broadcast_player_list();
+                                        }
+                                    }
+                                }
                             }
                             ClientRequest::MovesFrom(x, y) => {
-                                if player_num as u8 == self.game.current_position.whos_turn {
+                                if requester_seat == Some(self.game.current_position.whos_turn) {
                                     let mut possible_moves = Vec::new();
                                     let move_gen:&protochess_engine_rs::MoveGenerator = &MOVEGEN;
                                     for (from, to) in  move_gen.get_legal_moves_as_tuples(&mut self.game.current_position){
@@ -139,15 +265,341 @@ impl Room {
                 }
             }
 
-            //Leave if room is empty
-            if self.clients.len() == 0 {
+            //Leave if no human is left to drive the room; bots never issue
+            //requests, so a bot-only room would otherwise sit open forever
+            if !self.has_human_client() {
                 break;
             }
         }
     }
 
+    /// Whether any connected client is a human rather than a bot seat.
+    fn has_human_client(&self) -> bool {
+        self.clients.iter().any(|c| !c.is_bot())
+    }
+
+    /// Removes the client behind `id`, if connected: holds its seat in reserve,
+    /// promotes a new leader if the departing client was `clients[0]`, and warns
+    /// the other side if its seat just emptied out mid-game.
+    fn remove_client(&mut self, id: Uuid) -> Option<RemoveOutcome> {
+        let index = self.clients.iter().position(|x| x.id == id)?;
+        let was_leader = index == 0;
+        let client = self.clients.remove(index);
+        let vacated_seat = if let ClientRole::Player(seat) = client.role {
+            self.reserved.push(ReservedSeat {
+                token: client.token,
+                name: client.name,
+                role: client.role,
+                expires_at: Instant::now() + RECONNECT_GRACE,
+            });
+            Some(seat)
+        } else {
+            None
+        };
+        //A room of nothing but bots has no one left to lead or act on its behalf
+        let room_empty = !self.has_human_client();
+        let new_leader = if was_leader && !room_empty {
+            //Bots never issue requests, so leadership can only land on a human
+            if let Some(human_index) = self.clients.iter().position(|c| !c.is_bot()) {
+                if human_index != 0 {
+                    self.clients.swap(0, human_index);
+                }
+                Some(self.clients[0].name.clone())
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        self.broadcast_player_list();
+        if let Some(name) = &new_leader {
+            self.broadcast(ClientResponse::LeaderChanged { name: name.clone() });
+        }
+        //The other side can't keep playing against a seat that can never move
+        if self.in_progress && !room_empty {
+            if let Some(seat) = vacated_seat {
+                if self.seat_client_index(seat).is_none() {
+                    self.broadcast(ClientResponse::SeatVacated { seat });
+                }
+            }
+        }
+
+        Some(RemoveOutcome { was_leader, room_empty, new_leader })
+    }
+
+    /// Resolves to the earliest reservation expiry if any, or never, so it can
+    /// sit in a `tokio::select!` branch without spinning when nothing is reserved.
+    async fn wait_for_reservation_expiry(deadline: Option<Instant>) {
+        match deadline {
+            Some(instant) => tokio::time::sleep_until(TokioInstant::from_std(instant)).await,
+            None => std::future::pending::<()>().await,
+        }
+    }
+
+    /// Drops any reservations whose grace period has elapsed; if one covered a
+    /// seat still empty in an in-progress game, the match is aborted rather than
+    /// left waiting forever on a turn that can't come.
+    fn expire_reservations(&mut self) {
+        let now = Instant::now();
+        let (expired, still_reserved): (Vec<_>, Vec<_>) =
+            self.reserved.drain(..).partition(|r| r.expires_at <= now);
+        self.reserved = still_reserved;
+        for seat in expired {
+            if let ClientRole::Player(n) = seat.role {
+                if self.in_progress && self.seat_client_index(n).is_none() {
+                    self.in_progress = false;
+                    self.broadcast(ClientResponse::GameAborted {
+                        reason: format!("{} did not reconnect in time", seat.name),
+                    });
+                }
+            }
+        }
+    }
+
+    /// The lowest-numbered seat with no player or bot sitting in it, and not
+    /// currently held in reserve for a disconnected player's reconnect.
+    fn next_open_seat(&self) -> Option<u8> {
+        (0..NUM_SEATS).find(|seat| self.seat_client_index(*seat).is_none() && !self.seat_reserved(*seat))
+    }
+
+    /// The index into `clients` of whoever occupies `seat`, if it's taken.
+    fn seat_client_index(&self, seat: u8) -> Option<usize> {
+        self.clients.iter().position(|c| c.role == ClientRole::Player(seat))
+    }
+
+    /// Whether `seat` is held in reserve for a disconnected player's `Resume`.
+    fn seat_reserved(&self, seat: u8) -> bool {
+        let now = Instant::now();
+        self.reserved.iter().any(|r| r.role == ClientRole::Player(seat) && r.expires_at > now)
+    }
+
+    fn seat_has_bot(&self, seat: u8) -> bool {
+        self.clients.iter().any(|c| c.role == ClientRole::Player(seat) && c.is_bot())
+    }
+
+    /// How many clients (the team) currently occupy `seat`.
+    fn team_size(&self, seat: u8) -> usize {
+        self.clients.iter().filter(|c| c.role == ClientRole::Player(seat)).count()
+    }
+
+    /// The names of everyone occupying `seat`, joined for display as a winner/loser.
+    fn team_name(&self, seat: u8) -> String {
+        self.clients.iter()
+            .filter(|c| c.role == ClientRole::Player(seat))
+            .map(|c| c.name.clone())
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+
+    fn votes_needed(&self, seat: u8) -> usize {
+        let size = self.team_size(seat).max(1);
+        match self.config.vote_threshold {
+            VoteThreshold::Majority => size / 2 + 1,
+            VoteThreshold::Unanimous => size,
+        }
+    }
+
+    /// Resolves to the vote deadline if one is set, or never, so it can sit in a
+    /// `tokio::select!` branch alongside `rx.recv()` without spinning when idle.
+    async fn wait_for_vote_deadline(deadline: Option<TokioInstant>) {
+        match deadline {
+            Some(instant) => tokio::time::sleep_until(instant).await,
+            None => std::future::pending::<()>().await,
+        }
+    }
+
+    /// Records a vote for `turn` from `voter` on behalf of `seat`, rejecting moves
+    /// that aren't currently legal, and commits it once the side's threshold is met.
+    fn register_vote(&mut self, seat: u8, turn: Turn, voter: Uuid) {
+        let legal = {
+            let move_gen: &protochess_engine_rs::MoveGenerator = &MOVEGEN;
+            move_gen.get_legal_moves_as_tuples(&mut self.game.current_position)
+        };
+        if !legal.contains(&(turn.from, turn.to)) {
+            return;
+        }
+        let key: MoveKey = (turn.from, turn.to, turn.promote_to);
+        //A voter backs only one candidate move per ply; drop any earlier pick
+        //so a change of mind doesn't leave a stale vote tipping the plurality.
+        for (mv, voters) in self.vote_tally.iter_mut() {
+            if *mv != key {
+                voters.remove(&voter);
+            }
+        }
+        self.vote_tally.retain(|_, voters| !voters.is_empty());
+        self.vote_tally.entry(key).or_insert_with(HashSet::new).insert(voter);
+        if self.vote_deadline.is_none() {
+            self.vote_deadline = Some(TokioInstant::now() + VOTE_TIMEOUT);
+        }
+        self.broadcast_vote_state();
+
+        let needed = self.votes_needed(seat);
+        if let Some(winning_move) = self.vote_tally.iter().find(|(_, voters)| voters.len() >= needed).map(|(mv, _)| *mv) {
+            self.commit_move(seat, winning_move);
+        }
+    }
+
+    /// Auto-commits whichever candidate move currently has the most votes, called
+    /// when `VOTE_TIMEOUT` elapses without the side's team reaching its threshold.
+    fn resolve_vote_timeout(&mut self) {
+        let seat = self.game.get_whos_turn();
+        if let Some(plurality) = self.vote_tally.iter().max_by_key(|(_, voters)| voters.len()).map(|(mv, _)| *mv) {
+            self.commit_move(seat, plurality);
+        } else {
+            self.clear_vote_tally();
+        }
+    }
+
+    /// Plays `mv` for `seat`, exactly as a human `TakeTurn` used to do directly,
+    /// then clears the tally so the next ply starts with a clean vote.
+    fn commit_move(&mut self, seat: u8, mv: MoveKey) {
+        let (from, to, promote_to) = mv;
+        let (x1, y1) = from;
+        let (x2, y2) = to;
+        let move_gen: &protochess_engine_rs::MoveGenerator = &MOVEGEN;
+        if self.game.make_move(move_gen, x1, y1, x2, y2) {
+            println!("Move successful");
+            self.last_turn = Some(Turn { promote_to, from, to });
+
+            //Calculate if the position is in check after making this move
+            self.to_move_in_check = move_gen.in_check(&mut self.game.current_position);
+            if self.to_move_in_check && move_gen.count_legal_moves(&mut self.game.current_position) == 0 {
+                //We have a winner!
+                self.winner = Some(self.team_name(seat));
+            }
+            //See if we have any more moves
+            self.broadcast_game_update();
+
+            //Let any bot seats play themselves out until a human is to move
+            self.play_bot_turns();
+        }
+        self.clear_vote_tally();
+    }
+
+    fn clear_vote_tally(&mut self) {
+        self.vote_tally.clear();
+        self.vote_deadline = None;
+    }
+
+    fn broadcast_vote_state(&self) {
+        let votes = self.vote_tally.iter()
+            .map(|((from, to, promote_to), voters)| VoteCount {
+                from: *from,
+                to: *to,
+                promote_to: *promote_to,
+                count: voters.len() as u8,
+            })
+            .collect();
+        self.broadcast(ClientResponse::VoteState {
+            to_move: self.game.get_whos_turn(),
+            votes,
+        });
+    }
+
+    /// Diffs the position against the last broadcast snapshot and sends everyone
+    /// only what changed, instead of re-sending the whole board on every move.
     fn broadcast_game_update(&mut self){
-        self.broadcast(self.serialize_game());
+        let new_snapshot = self.game.current_position.pieces_as_tuples();
+        let added_or_moved = new_snapshot.iter()
+            .filter(|p| !self.last_snapshot.contains(p))
+            .map(|&(owner, x, y, piece_type)| Piece { owner, x, y, piece_type })
+            .collect();
+        let removed = self.last_snapshot.iter()
+            .filter(|p| !new_snapshot.contains(p))
+            .map(|&(_, x, y, _)| (x, y))
+            .collect();
+        self.last_snapshot = new_snapshot;
+        self.broadcast(ClientResponse::GameDelta {
+            added_or_moved,
+            removed,
+            to_move: self.game.current_position.whos_turn,
+            to_move_in_check: self.to_move_in_check,
+            last_turn: self.last_turn.clone(),
+            winner: self.winner.clone(),
+        });
+    }
+
+    /// Builds the starting position described by `config`. A leader-supplied FEN
+    /// can fail to parse, so this returns a `Result` instead of panicking the
+    /// room task the way an `unwrap()` on untrusted input would.
+    fn build_game_from(config: &GameConfig) -> Result<protochess_engine_rs::Game, String> {
+        if !config.custom_pieces.is_empty() {
+            // Neither construction path below (FEN or default) has any way to
+            // register a custom piece's movement pattern, so this would be
+            // silently dropped whether or not a FEN is also set. Refuse it
+            // unconditionally rather than only in the no-FEN case.
+            return Err("custom_pieces aren't supported by this engine build".to_string());
+        }
+        if let Some(fen) = &config.fen {
+            return match fen.parse() {
+                Ok(parsed) => Ok(protochess_engine_rs::Game::from_fen(parsed)),
+                Err(_) => Err(format!("invalid FEN: {}", fen)),
+            };
+        }
+        if config.width != 8 || config.height != 8 {
+            // The engine only exposes board construction via `Game::from_fen`/
+            // `Game::default`; there's no constructor for a custom size without
+            // a FEN to drive it. Rather than silently fall back to the standard
+            // position and pretend the config was honored, refuse it here so
+            // the leader isn't told settings are in effect that `StartGame`
+            // would actually ignore.
+            return Err("custom width/height require a starting FEN".to_string());
+        }
+        Ok(protochess_engine_rs::Game::default())
+    }
+
+    /// Builds the starting position from the locked-in lobby config.
+    fn build_game(&self) -> Result<protochess_engine_rs::Game, String> {
+        Self::build_game_from(&self.config)
+    }
+
+    /// Runs moves for as long as the side to move is a bot seat, applying each
+    /// one to `self.game` the same way a human `TakeTurn` would.
+    ///
+    /// There's no confirmed move-search entry point on `Position`/`MoveGenerator`
+    /// in this tree: the engine crate ships no source beyond `main.rs`, and the
+    /// only documented search call there, `Engine::play_best_move(depth)`, is on
+    /// a type `Room` doesn't hold. Rather than call an engine method that could
+    /// not be verified and might not even compile, a bot plays the first move
+    /// `get_legal_moves_as_tuples` returns, the same already-relied-on API used
+    /// by `MovesFrom` and vote legality checks. `depth` is kept on the `Client`
+    /// for display and for a real search to use once the engine exposes one.
+    fn play_bot_turns(&mut self) {
+        if !self.in_progress {
+            return;
+        }
+        let move_gen: &protochess_engine_rs::MoveGenerator = &MOVEGEN;
+        loop {
+            if self.winner.is_some() {
+                break;
+            }
+            let seat = self.game.get_whos_turn();
+            let bot_index = match self.seat_client_index(seat) {
+                Some(index) => index,
+                None => break,
+            };
+            if self.clients[bot_index].bot_depth().is_none() {
+                break;
+            }
+            let (from, to) = match move_gen.get_legal_moves_as_tuples(&mut self.game.current_position).into_iter().next() {
+                Some(mv) => mv,
+                None => break,
+            };
+            let (x1, y1) = from;
+            let (x2, y2) = to;
+            if !self.game.make_move(move_gen, x1, y1, x2, y2) {
+                break;
+            }
+            println!("bot at seat {} plays {:?} -> {:?}", seat, from, to);
+            self.last_turn = Some(Turn { promote_to: None, from, to });
+
+            self.to_move_in_check = move_gen.in_check(&mut self.game.current_position);
+            if self.to_move_in_check && move_gen.count_legal_moves(&mut self.game.current_position) == 0 {
+                self.winner = Some(self.clients[bot_index].name.clone());
+            }
+            self.broadcast_game_update();
+        }
     }
 
     fn serialize_game(&self) -> ClientResponse {
@@ -223,12 +675,32 @@ impl Room {
     }
 
     fn broadcast_player_list(&self){
-        for (i, client) in self.clients.iter().enumerate() {
-            client.try_send(ClientResponse::PlayerList {
-                player_num: i as u8,
-                you: format!("{}", client.name),
-                names: self.clients.iter().map(|x| x.name.clone()).collect()
-            });
+        for client in &self.clients {
+            let player_num = match client.role {
+                ClientRole::Player(seat) => Some(seat),
+                ClientRole::Spectator => None,
+            };
+            client.try_send(self.player_list_response(player_num, &client.name));
+        }
+    }
+
+    /// Builds a `PlayerList` response as seen by a client sitting in `player_num` (or
+    /// spectating), listing seated players in seat order and spectators separately.
+    fn player_list_response(&self, player_num: Option<u8>, you: &str) -> ClientResponse {
+        let players = (0..NUM_SEATS)
+            .flat_map(|seat| self.clients.iter()
+                .filter(move |c| c.role == ClientRole::Player(seat))
+                .map(|c| c.name.clone()))
+            .collect();
+        let spectators = self.clients.iter()
+            .filter(|c| c.role == ClientRole::Spectator)
+            .map(|c| c.name.clone())
+            .collect();
+        ClientResponse::PlayerList {
+            player_num,
+            you: you.to_string(),
+            players,
+            spectators,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use uuid::Uuid;
+use crate::client::Client;
+use crate::client_message::ClientRequest;
+
+pub enum RoomMessage {
+    AddClient(Client),
+    RemoveClient(Uuid),
+    External(Uuid, ClientRequest),
+}